@@ -1,17 +1,33 @@
-use std::fs::File;
-use std::io::prelude::*;
+// Cargo.toml:
+// [dependencies]
+// rand = "0.8.3"
 use std::io;
 use std::cmp::Ordering;
+use rand::Rng;
+
+const LOW: u32 = 1;
+const HIGH: u32 = 100;
+
+enum GameResult {
+    Won,
+    Lost,
+}
 
 fn main() {
     println!("Guess the number!");
+    println!("Guess a number between {} and {}", LOW, HIGH);
 
-    let mut buffer = [0; 1];
-    File::open("/dev/urandom").unwrap().read(&mut buffer).unwrap();
-    let secret_number = (buffer[0] as u32 % 100) + 1;
+    let secret_number = rand::thread_rng().gen_range(LOW..=HIGH);
+
+    let max_tries = 10;
+    let mut tries = 0;
+
+    let result = loop {
+        if tries == max_tries {
+            break GameResult::Lost;
+        }
 
-    loop {
-        println!("Please input your guess.");
+        println!("Please input your guess. ({} tries left)", max_tries - tries);
 
         let mut guess = String::new();
 
@@ -20,18 +36,30 @@ fn main() {
 
         let guess: u32 = match guess.trim().parse() {
             Ok(num) => num,
-            Err(_) => continue,
+            Err(_) => {
+                println!("Please type a number!");
+                continue;
+            }
         };
 
+        if !(LOW..=HIGH).contains(&guess) {
+            println!("Please guess a number between {} and {}!", LOW, HIGH);
+            continue;
+        }
+
+        tries += 1;
+
         println!("You guessed: {}", guess);
 
         match guess.cmp(&secret_number) {
             Ordering::Less    => println!("Too small!"),
             Ordering::Greater => println!("Too big!"),
-            Ordering::Equal   => {
-                println!("You win!");
-                break;
-            }
+            Ordering::Equal   => break GameResult::Won,
         }
+    };
+
+    match result {
+        GameResult::Won => println!("You win!"),
+        GameResult::Lost => println!("You lose! The number was {}.", secret_number),
     }
 }